@@ -3,9 +3,125 @@ use super::{
     HighSpeed, LowSpeed,
 };
 use crate::gpio::{OutputPin, OutputSignal};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use critical_section::Mutex;
 use esp32::ledc::RegisterBlock;
 use paste::paste;
 
+/// Which LEDC channel bank a [`Number`] refers to. HighSpeed and LowSpeed each
+/// have their own bank of 8 channels, so the two must be distinguished when
+/// indexing per-channel state shared across both banks (e.g. [`FADE_WAKERS`]).
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ChannelBank {
+    HighSpeed,
+    LowSpeed,
+}
+
+/// Per-channel wakers for [`FadeDoneFuture`], serviced by [`handle_fade_interrupt`]
+/// which the LEDC interrupt handler is expected to call on every `duty_chng_end`
+/// interrupt. Indexed by [`fade_waker_index`]: slots 0..8 are the HighSpeed
+/// bank, 8..16 are the LowSpeed bank.
+static FADE_WAKERS: [Mutex<RefCell<Option<Waker>>>; 16] = [
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+    Mutex::new(RefCell::new(None)),
+];
+
+/// Per-channel "fade actually finished" flags, set by [`handle_fade_interrupt`]
+/// and consumed by [`FadeDoneFuture::poll`]. A separate flag is needed instead
+/// of re-reading the hardware status bit from `poll`, because
+/// `handle_fade_interrupt` clears that bit (so the level-sensitive LEDC
+/// interrupt doesn't immediately re-fire) before waking the task, so by the
+/// time `poll` runs the bit is already gone. Indexed the same way as
+/// [`FADE_WAKERS`].
+static FADE_DONE: [Mutex<Cell<bool>>; 16] = [
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+    Mutex::new(Cell::new(false)),
+];
+
+/// Index into [`FADE_WAKERS`] for a given bank/channel combination
+fn fade_waker_index(bank: ChannelBank, number: Number) -> usize {
+    let base = match bank {
+        ChannelBank::HighSpeed => 0,
+        ChannelBank::LowSpeed => 8,
+    };
+    base + number as usize
+}
+
+/// Service a pending fade-done interrupt for `number` on channel bank `bank`:
+/// clears the hardware status bit (so the level-sensitive LEDC interrupt does
+/// not immediately re-fire) and wakes the corresponding [`FadeDoneFuture`] if
+/// one is being awaited. Call this from the LEDC interrupt handler.
+pub fn handle_fade_interrupt(bank: ChannelBank, number: Number) {
+    let ledc = unsafe { &*esp32::LEDC::ptr() };
+
+    // Field names are spelled out instead of going through the `clear_fade_interrupt!`
+    // macro used by `ChannelHW`, since that macro is only declared further down the
+    // file (macro_rules! are textually scoped).
+    match bank {
+        ChannelBank::HighSpeed => match number {
+            Number::Channel0 => ledc.int_clr.write(|w| w.duty_chng_end_hsch0_int_clr().set_bit()),
+            Number::Channel1 => ledc.int_clr.write(|w| w.duty_chng_end_hsch1_int_clr().set_bit()),
+            Number::Channel2 => ledc.int_clr.write(|w| w.duty_chng_end_hsch2_int_clr().set_bit()),
+            Number::Channel3 => ledc.int_clr.write(|w| w.duty_chng_end_hsch3_int_clr().set_bit()),
+            Number::Channel4 => ledc.int_clr.write(|w| w.duty_chng_end_hsch4_int_clr().set_bit()),
+            Number::Channel5 => ledc.int_clr.write(|w| w.duty_chng_end_hsch5_int_clr().set_bit()),
+            Number::Channel6 => ledc.int_clr.write(|w| w.duty_chng_end_hsch6_int_clr().set_bit()),
+            Number::Channel7 => ledc.int_clr.write(|w| w.duty_chng_end_hsch7_int_clr().set_bit()),
+        },
+        ChannelBank::LowSpeed => match number {
+            Number::Channel0 => ledc.int_clr.write(|w| w.duty_chng_end_lsch0_int_clr().set_bit()),
+            Number::Channel1 => ledc.int_clr.write(|w| w.duty_chng_end_lsch1_int_clr().set_bit()),
+            Number::Channel2 => ledc.int_clr.write(|w| w.duty_chng_end_lsch2_int_clr().set_bit()),
+            Number::Channel3 => ledc.int_clr.write(|w| w.duty_chng_end_lsch3_int_clr().set_bit()),
+            Number::Channel4 => ledc.int_clr.write(|w| w.duty_chng_end_lsch4_int_clr().set_bit()),
+            Number::Channel5 => ledc.int_clr.write(|w| w.duty_chng_end_lsch5_int_clr().set_bit()),
+            Number::Channel6 => ledc.int_clr.write(|w| w.duty_chng_end_lsch6_int_clr().set_bit()),
+            Number::Channel7 => ledc.int_clr.write(|w| w.duty_chng_end_lsch7_int_clr().set_bit()),
+        },
+    }
+
+    let index = fade_waker_index(bank, number);
+    critical_section::with(|cs| {
+        FADE_DONE[index].borrow(cs).set(true);
+        if let Some(waker) = FADE_WAKERS[index].borrow(cs).borrow_mut().take() {
+            waker.wake();
+        }
+    });
+}
+
 /// Channel errors
 #[derive(Debug)]
 pub enum Error {
@@ -17,6 +133,13 @@ pub enum Error {
     Channel,
 }
 
+/// Fade direction, used to program the `duty_inc` bit
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+enum FadeDirection {
+    Increase,
+    Decrease,
+}
+
 /// Channel number
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub enum Number {
@@ -34,11 +157,28 @@ pub enum Number {
 pub mod config {
     use crate::ledc::timer::{TimerIFace, TimerSpeed};
 
+    /// Level the channel output is driven to while the channel is disabled
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub enum IdleLevel {
+        Low,
+        High,
+    }
+
+    impl Default for IdleLevel {
+        fn default() -> Self {
+            IdleLevel::Low
+        }
+    }
+
     /// Channel configuration
     #[derive(Copy, Clone)]
     pub struct Config<'a, S: TimerSpeed> {
         pub timer: &'a dyn TimerIFace<S>,
         pub duty_pct: f32,
+        /// Invert the generated signal at the channel output
+        pub invert_output: bool,
+        /// Level the channel output is driven to while disabled
+        pub idle_level: IdleLevel,
     }
 }
 
@@ -50,8 +190,39 @@ where
     /// Configure channel
     fn configure(&mut self, config: config::Config<'a, S>) -> Result<(), Error>;
 
-    /// Set channel duty HW
+    /// Set channel duty % of channel
     fn set_duty(&self, duty_pct: f32) -> Result<(), Error>;
+
+    /// Get the maximum duty value, i.e. the timer's `2^duty_exp` resolution
+    fn get_max_duty(&self) -> Result<u32, Error>;
+
+    /// Set the channel duty as a raw count of timer LSBs, in `0..=get_max_duty()`
+    fn set_duty_raw(&self, duty: u32) -> Result<(), Error>;
+
+    /// Start a hardware duty fade from `start_duty_pct` to `end_duty_pct` over
+    /// approximately `duration_ms` milliseconds, landing exactly on
+    /// `end_duty_pct`.
+    ///
+    /// The fade is driven entirely by the LEDC hardware: once started, the duty
+    /// ramps on its own without further CPU intervention. The span
+    /// `|end_duty_pct - start_duty_pct|` is limited to `0x3FF * 0x3FF` timer
+    /// LSBs (the product of the two 10-bit `duty_num`/`duty_scale` fade
+    /// fields); wider spans, which only occur with a high-resolution timer,
+    /// return [`Error::Duty`].
+    fn start_duty_fade(
+        &self,
+        start_duty_pct: f32,
+        end_duty_pct: f32,
+        duration_ms: u16,
+    ) -> Result<(), Error>;
+
+    /// Wait for the current hardware duty fade to complete.
+    ///
+    /// Resolves once [`handle_fade_interrupt`] has observed the channel's
+    /// `duty_chng_end` interrupt, so it must be polled from a task driven by
+    /// the LEDC interrupt (via [`handle_fade_interrupt`]) in order to make
+    /// progress.
+    fn wait_for_fade_done(&self) -> FadeDoneFuture<'_, 'a, S, O>;
 }
 
 /// Channel HW interface
@@ -61,6 +232,66 @@ pub trait ChannelHW<O: OutputPin> {
 
     /// Set channel duty HW
     fn set_duty_hw(&self, duty: u32);
+
+    /// Read back the channel duty HW, in raw timer LSBs
+    fn get_duty_hw(&self) -> u32;
+
+    /// Enable or disable the channel output
+    fn set_output_enabled_hw(&self, enabled: bool);
+
+    /// Set the channel's hpoint (active window start, in timer LSBs) and duty
+    /// together. Used to shift a channel's active window within the PWM
+    /// period, e.g. to build a [`ComplementaryChannel`] pair.
+    fn set_hpoint_and_duty_hw(&self, hpoint: u32, duty: u32);
+
+    /// Program and start a hardware duty fade
+    fn set_fade_hw(&self, start_duty: u32, direction: FadeDirection, duty_num: u32, duty_cycle: u32, duty_scale: u32);
+
+    /// Enable the fade-done (`duty_chng_end`) interrupt for this channel
+    fn enable_fade_interrupt(&self);
+
+    /// Clear a pending fade-done interrupt for this channel
+    fn clear_fade_interrupt(&self);
+
+    /// Check whether the fade-done interrupt status bit is set
+    fn is_fade_done(&self) -> bool;
+
+    /// This channel's index into [`FADE_WAKERS`]
+    fn fade_waker_index(&self) -> usize;
+}
+
+/// Future returned by [`ChannelIFace::wait_for_fade_done`]
+pub struct FadeDoneFuture<'ch, 'a, S: TimerSpeed, O: OutputPin>
+where
+    Channel<'a, S, O>: ChannelHW<O>,
+{
+    channel: &'ch Channel<'a, S, O>,
+}
+
+impl<'ch, 'a, S: TimerSpeed, O: OutputPin> Future for FadeDoneFuture<'ch, 'a, S, O>
+where
+    Channel<'a, S, O>: ChannelHW<O>,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let index = self.channel.fade_waker_index();
+
+        // Consume the flag `handle_fade_interrupt` set, rather than the
+        // hardware status bit: that bit is already cleared by the time the
+        // handler wakes us, so it would never be seen set here.
+        let done = critical_section::with(|cs| FADE_DONE[index].borrow(cs).replace(false));
+        if done {
+            return Poll::Ready(());
+        }
+
+        critical_section::with(|cs| {
+            *FADE_WAKERS[index].borrow(cs).borrow_mut() = Some(cx.waker().clone());
+        });
+        self.channel.enable_fade_interrupt();
+
+        Poll::Pending
+    }
 }
 
 /// Channel struct
@@ -69,6 +300,12 @@ pub struct Channel<'a, S: TimerSpeed, O: OutputPin> {
     timer: Option<&'a dyn TimerIFace<S>>,
     number: Number,
     output_pin: O,
+    invert_output: bool,
+    idle_level: config::IdleLevel,
+    // Cached result of `get_max_duty`, refreshed in `configure`, so the
+    // infallible `embedded_hal::PwmPin::get_max_duty` has something to return
+    // for an unconfigured channel instead of having to unwrap a `Result`.
+    max_duty: Cell<u32>,
 }
 
 impl<'a, S: TimerSpeed, O: OutputPin> Channel<'a, S, O> {
@@ -80,6 +317,9 @@ impl<'a, S: TimerSpeed, O: OutputPin> Channel<'a, S, O> {
             timer: None,
             number,
             output_pin,
+            invert_output: false,
+            idle_level: config::IdleLevel::default(),
+            max_duty: Cell::new(0),
         }
     }
 }
@@ -91,6 +331,9 @@ where
     /// Configure channel
     fn configure(&mut self, config: config::Config<'a, S>) -> Result<(), Error> {
         self.timer = Some(config.timer);
+        self.invert_output = config.invert_output;
+        self.idle_level = config.idle_level;
+        self.max_duty.set(self.get_max_duty()?);
 
         self.set_duty(config.duty_pct)?;
         self.configure_hw()?;
@@ -100,29 +343,121 @@ where
 
     /// Set duty % of channel
     fn set_duty(&self, duty_pct: f32) -> Result<(), Error> {
-        let duty_exp;
+        if duty_pct > 1.0 {
+            return Err(Error::Duty);
+        }
+
+        let max_duty = self.get_max_duty()?;
+        let duty_value = (max_duty as f32 * duty_pct) as u32;
+
+        if duty_value == 0 {
+            // Not enough bits to represent the requested duty %
+            return Err(Error::Duty);
+        }
+
+        self.set_duty_raw(duty_value)
+    }
+
+    /// Get the maximum duty value, i.e. the timer's `2^duty_exp` resolution
+    fn get_max_duty(&self) -> Result<u32, Error> {
         if let Some(timer) = self.timer {
-            if let Some(timer_duty) = timer.get_duty() {
-                duty_exp = timer_duty as u32;
+            if let Some(duty_exp) = timer.get_duty() {
+                Ok(2_u32.pow(duty_exp as u32))
             } else {
-                return Err(Error::Timer);
+                Err(Error::Timer)
             }
+        } else {
+            Err(Error::Channel)
+        }
+    }
+
+    /// Set the channel duty as a raw count of timer LSBs, in `0..=get_max_duty()`
+    fn set_duty_raw(&self, duty: u32) -> Result<(), Error> {
+        let max_duty = self.get_max_duty()?;
+
+        if duty > max_duty {
+            return Err(Error::Duty);
+        }
+
+        self.set_duty_hw(duty);
+
+        Ok(())
+    }
+
+    /// Start a hardware duty fade from `start_duty_pct` to `end_duty_pct` over
+    /// approximately `duration_ms` milliseconds.
+    fn start_duty_fade(
+        &self,
+        start_duty_pct: f32,
+        end_duty_pct: f32,
+        duration_ms: u16,
+    ) -> Result<(), Error> {
+        if start_duty_pct > 1.0 || end_duty_pct > 1.0 {
+            return Err(Error::Duty);
+        }
+
+        let max_duty = self.get_max_duty()?;
+        let frequency = if let Some(timer) = self.timer {
+            timer.get_frequency().ok_or(Error::Timer)?
         } else {
             return Err(Error::Channel);
+        };
+
+        let start_duty = (max_duty as f32 * start_duty_pct) as u32;
+        let end_duty = (max_duty as f32 * end_duty_pct) as u32;
+
+        let (direction, delta) = if end_duty >= start_duty {
+            (FadeDirection::Increase, end_duty - start_duty)
+        } else {
+            (FadeDirection::Decrease, start_duty - end_duty)
+        };
+
+        if delta == 0 {
+            // Nothing to ramp, just jump straight to the target duty.
+            self.set_duty_hw(start_duty);
+            return Ok(());
+        }
+
+        // `duty_num`, `duty_cycle` and `duty_scale` are all 10-bit fields, so
+        // each can only hold values up to `MAX_FIELD`. A fade of one LSB per
+        // step (`duty_scale == 1`) is the smoothest ramp, but if it spans more
+        // than `MAX_FIELD` LSBs `duty_num` would overflow and silently
+        // truncate. Grow `duty_scale` just enough to bring the step count
+        // back under the limit.
+        const MAX_FIELD: u32 = 0x3FF;
+        let duty_scale = (delta + MAX_FIELD - 1) / MAX_FIELD;
+        if duty_scale > MAX_FIELD {
+            return Err(Error::Duty);
         }
+        let duty_num = delta / duty_scale;
 
-        let duty_range = 2_u32.pow(duty_exp);
-        let duty_value = (duty_range as f32 * duty_pct) as u32;
+        // `duty_num * duty_scale` can undershoot `delta` by up to
+        // `duty_scale - 1` LSBs when it doesn't divide evenly. Apply that
+        // remainder as an immediate jump before the hardware fade starts, so
+        // the ramp still lands exactly on `end_duty` instead of stopping
+        // short of it.
+        let remainder = delta - duty_num * duty_scale;
+        let fade_start_duty = match direction {
+            FadeDirection::Increase => start_duty + remainder,
+            FadeDirection::Decrease => start_duty - remainder,
+        };
 
-        if duty_value == 0 || duty_pct > 1.0 {
-            // Not enough bits to represent the requested duty % or duty_pct greater than 1.0
+        let total_cycles = frequency.raw() as u64 * duration_ms as u64 / 1000;
+        let duty_cycle = core::cmp::max(1, total_cycles / duty_num as u64);
+        if duty_cycle > MAX_FIELD as u64 {
             return Err(Error::Duty);
         }
+        let duty_cycle = duty_cycle as u32;
 
-        self.set_duty_hw(duty_value);
+        self.set_fade_hw(fade_start_duty, direction, duty_num, duty_cycle, duty_scale);
 
         Ok(())
     }
+
+    /// Wait for the current hardware duty fade to complete.
+    fn wait_for_fade_done(&self) -> FadeDoneFuture<'_, 'a, S, O> {
+        FadeDoneFuture { channel: self }
+    }
 }
 
 /// Macro to configure channel parameters in hw
@@ -136,6 +471,10 @@ macro_rules! set_channel {
                     .set_bit()
                     .[<timer_sel_ $speed sch $num>]()
                     .bits($channel_number)
+                    .[<idle_lv_ $speed sch $num>]()
+                    .bit($self.idle_level == config::IdleLevel::High)
+                    .[<out_inv_ $speed sch $num>]()
+                    .bit($self.invert_output)
             });
             $self.ledc.[<$speed sch $num _conf1>].write(|w| unsafe {
                 w.[<duty_start_ $speed sch $num>]()
@@ -164,6 +503,90 @@ macro_rules! set_duty {
     };
 }
 
+/// Macro to read back a channel's duty HW
+macro_rules! get_duty {
+    ( $self: ident, $speed: ident, $num: literal ) => {
+        paste! {
+            $self.ledc.[<$speed sch $num _duty>].read().[<duty_ $speed sch $num>]().bits() >> 4
+        }
+    };
+}
+
+/// Macro to enable/disable a channel's output
+macro_rules! set_output_enabled {
+    ( $self: ident, $speed: ident, $num: literal, $enabled: expr ) => {
+        paste! {
+            $self.ledc
+                .[<$speed sch $num _conf0>]
+                .modify(|_, w| w.[<sig_out_en_ $speed sch $num>]().bit($enabled));
+        }
+    };
+}
+
+/// Macro to set the hpoint and duty registers of a channel together, used to
+/// shift a channel's active window within the PWM period.
+macro_rules! set_hpoint_and_duty {
+    ( $self: ident, $speed: ident, $num: literal, $hpoint: ident, $duty: ident ) => {
+        paste! {
+            $self.ledc.[<$speed sch $num _hpoint>]
+                .write(|w| unsafe { w.[<hpoint_ $speed sch $num>]().bits($hpoint as u16) });
+            set_duty!($self, $speed, $num, $duty);
+        }
+    };
+}
+
+/// Macro to program and start a hardware duty fade
+macro_rules! set_fade {
+    ( $self: ident, $speed: ident, $num: literal, $duty: ident, $duty_inc: ident, $duty_num: ident, $duty_cycle: ident, $duty_scale: ident ) => {
+        paste! {
+            $self.ledc
+                .[<$speed sch $num _duty>]
+                .write(|w| unsafe { w.[<duty_ $speed sch $num>]().bits($duty << 4) });
+            $self.ledc.[<$speed sch $num _conf1>].write(|w| unsafe {
+                w.[<duty_start_ $speed sch $num>]()
+                    .set_bit()
+                    .[<duty_inc_ $speed sch $num>]()
+                    .bit($duty_inc)
+                    .[<duty_num_ $speed sch $num>]()
+                    .bits($duty_num as u16)
+                    .[<duty_cycle_ $speed sch $num>]()
+                    .bits($duty_cycle as u16)
+                    .[<duty_scale_ $speed sch $num>]()
+                    .bits($duty_scale as u16)
+                });
+        }
+    };
+}
+
+/// Macro to enable/disable the fade-done interrupt for a channel
+macro_rules! set_fade_interrupt_enabled {
+    ( $ledc: expr, $speed: ident, $num: literal, $enable: expr ) => {
+        paste! {
+            $ledc.int_ena.modify(|_, w| w.[<duty_chng_end_ $speed sch $num _int_ena>]().bit($enable));
+        }
+    };
+}
+
+/// Macro to clear a pending fade-done interrupt for a channel
+macro_rules! clear_fade_interrupt {
+    ( $ledc: expr, $speed: ident, $num: literal ) => {
+        paste! {
+            $ledc
+                .int_clr
+                .write(|w| w.[<duty_chng_end_ $speed sch $num _int_clr>]().set_bit());
+        }
+    };
+}
+
+/// Macro to read the fade-done interrupt status bit for a channel
+macro_rules! is_fade_interrupt_set {
+    ( $ledc: expr, $speed: ident, $num: literal ) => {
+        paste! {
+            $ledc.int_st.read().[<duty_chng_end_ $speed sch $num _int_st>]().bit_is_set()
+        }
+    };
+}
+
 /// Macro to update channel configuration (only for LowSpeed channels)
 macro_rules! update_channel {
     ( $self: ident, $num: literal) => {
@@ -241,6 +664,110 @@ impl<'a, O: OutputPin> ChannelHW<O> for Channel<'a, HighSpeed, O> {
             Number::Channel7 => set_duty!(self, h, 7, duty),
         };
     }
+
+    /// Read back the channel duty HW, in raw timer LSBs
+    fn get_duty_hw(&self) -> u32 {
+        match self.number {
+            Number::Channel0 => get_duty!(self, h, 0),
+            Number::Channel1 => get_duty!(self, h, 1),
+            Number::Channel2 => get_duty!(self, h, 2),
+            Number::Channel3 => get_duty!(self, h, 3),
+            Number::Channel4 => get_duty!(self, h, 4),
+            Number::Channel5 => get_duty!(self, h, 5),
+            Number::Channel6 => get_duty!(self, h, 6),
+            Number::Channel7 => get_duty!(self, h, 7),
+        }
+    }
+
+    /// Enable or disable the channel output
+    fn set_output_enabled_hw(&self, enabled: bool) {
+        match self.number {
+            Number::Channel0 => set_output_enabled!(self, h, 0, enabled),
+            Number::Channel1 => set_output_enabled!(self, h, 1, enabled),
+            Number::Channel2 => set_output_enabled!(self, h, 2, enabled),
+            Number::Channel3 => set_output_enabled!(self, h, 3, enabled),
+            Number::Channel4 => set_output_enabled!(self, h, 4, enabled),
+            Number::Channel5 => set_output_enabled!(self, h, 5, enabled),
+            Number::Channel6 => set_output_enabled!(self, h, 6, enabled),
+            Number::Channel7 => set_output_enabled!(self, h, 7, enabled),
+        };
+    }
+
+    /// Set the channel's hpoint and duty together
+    fn set_hpoint_and_duty_hw(&self, hpoint: u32, duty: u32) {
+        match self.number {
+            Number::Channel0 => set_hpoint_and_duty!(self, h, 0, hpoint, duty),
+            Number::Channel1 => set_hpoint_and_duty!(self, h, 1, hpoint, duty),
+            Number::Channel2 => set_hpoint_and_duty!(self, h, 2, hpoint, duty),
+            Number::Channel3 => set_hpoint_and_duty!(self, h, 3, hpoint, duty),
+            Number::Channel4 => set_hpoint_and_duty!(self, h, 4, hpoint, duty),
+            Number::Channel5 => set_hpoint_and_duty!(self, h, 5, hpoint, duty),
+            Number::Channel6 => set_hpoint_and_duty!(self, h, 6, hpoint, duty),
+            Number::Channel7 => set_hpoint_and_duty!(self, h, 7, hpoint, duty),
+        };
+    }
+
+    /// Program and start a hardware duty fade
+    fn set_fade_hw(&self, start_duty: u32, direction: FadeDirection, duty_num: u32, duty_cycle: u32, duty_scale: u32) {
+        let duty_inc = direction == FadeDirection::Increase;
+        match self.number {
+            Number::Channel0 => set_fade!(self, h, 0, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel1 => set_fade!(self, h, 1, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel2 => set_fade!(self, h, 2, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel3 => set_fade!(self, h, 3, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel4 => set_fade!(self, h, 4, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel5 => set_fade!(self, h, 5, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel6 => set_fade!(self, h, 6, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+            Number::Channel7 => set_fade!(self, h, 7, start_duty, duty_inc, duty_num, duty_cycle, duty_scale),
+        };
+    }
+
+    /// Enable the fade-done (`duty_chng_end`) interrupt for this channel
+    fn enable_fade_interrupt(&self) {
+        match self.number {
+            Number::Channel0 => set_fade_interrupt_enabled!(self.ledc, h, 0, true),
+            Number::Channel1 => set_fade_interrupt_enabled!(self.ledc, h, 1, true),
+            Number::Channel2 => set_fade_interrupt_enabled!(self.ledc, h, 2, true),
+            Number::Channel3 => set_fade_interrupt_enabled!(self.ledc, h, 3, true),
+            Number::Channel4 => set_fade_interrupt_enabled!(self.ledc, h, 4, true),
+            Number::Channel5 => set_fade_interrupt_enabled!(self.ledc, h, 5, true),
+            Number::Channel6 => set_fade_interrupt_enabled!(self.ledc, h, 6, true),
+            Number::Channel7 => set_fade_interrupt_enabled!(self.ledc, h, 7, true),
+        };
+    }
+
+    /// Clear a pending fade-done interrupt for this channel
+    fn clear_fade_interrupt(&self) {
+        match self.number {
+            Number::Channel0 => clear_fade_interrupt!(self.ledc, h, 0),
+            Number::Channel1 => clear_fade_interrupt!(self.ledc, h, 1),
+            Number::Channel2 => clear_fade_interrupt!(self.ledc, h, 2),
+            Number::Channel3 => clear_fade_interrupt!(self.ledc, h, 3),
+            Number::Channel4 => clear_fade_interrupt!(self.ledc, h, 4),
+            Number::Channel5 => clear_fade_interrupt!(self.ledc, h, 5),
+            Number::Channel6 => clear_fade_interrupt!(self.ledc, h, 6),
+            Number::Channel7 => clear_fade_interrupt!(self.ledc, h, 7),
+        };
+    }
+
+    /// Check whether the fade-done interrupt status bit is set
+    fn is_fade_done(&self) -> bool {
+        match self.number {
+            Number::Channel0 => is_fade_interrupt_set!(self.ledc, h, 0),
+            Number::Channel1 => is_fade_interrupt_set!(self.ledc, h, 1),
+            Number::Channel2 => is_fade_interrupt_set!(self.ledc, h, 2),
+            Number::Channel3 => is_fade_interrupt_set!(self.ledc, h, 3),
+            Number::Channel4 => is_fade_interrupt_set!(self.ledc, h, 4),
+            Number::Channel5 => is_fade_interrupt_set!(self.ledc, h, 5),
+            Number::Channel6 => is_fade_interrupt_set!(self.ledc, h, 6),
+            Number::Channel7 => is_fade_interrupt_set!(self.ledc, h, 7),
+        }
+    }
+
+    /// This channel's index into [`FADE_WAKERS`]
+    fn fade_waker_index(&self) -> usize {
+        fade_waker_index(ChannelBank::HighSpeed, self.number)
+    }
 }
 
 /// Channel HW interface for LowSpeed channels
@@ -317,4 +844,293 @@ impl<'a, O: OutputPin> ChannelHW<O> for Channel<'a, LowSpeed, O> {
             Number::Channel7 => set_duty!(self, l, 7, duty),
         };
     }
+
+    /// Read back the channel duty HW, in raw timer LSBs
+    fn get_duty_hw(&self) -> u32 {
+        match self.number {
+            Number::Channel0 => get_duty!(self, l, 0),
+            Number::Channel1 => get_duty!(self, l, 1),
+            Number::Channel2 => get_duty!(self, l, 2),
+            Number::Channel3 => get_duty!(self, l, 3),
+            Number::Channel4 => get_duty!(self, l, 4),
+            Number::Channel5 => get_duty!(self, l, 5),
+            Number::Channel6 => get_duty!(self, l, 6),
+            Number::Channel7 => get_duty!(self, l, 7),
+        }
+    }
+
+    /// Enable or disable the channel output
+    fn set_output_enabled_hw(&self, enabled: bool) {
+        match self.number {
+            Number::Channel0 => {
+                set_output_enabled!(self, l, 0, enabled);
+                update_channel!(self, 0);
+            }
+            Number::Channel1 => {
+                set_output_enabled!(self, l, 1, enabled);
+                update_channel!(self, 1);
+            }
+            Number::Channel2 => {
+                set_output_enabled!(self, l, 2, enabled);
+                update_channel!(self, 2);
+            }
+            Number::Channel3 => {
+                set_output_enabled!(self, l, 3, enabled);
+                update_channel!(self, 3);
+            }
+            Number::Channel4 => {
+                set_output_enabled!(self, l, 4, enabled);
+                update_channel!(self, 4);
+            }
+            Number::Channel5 => {
+                set_output_enabled!(self, l, 5, enabled);
+                update_channel!(self, 5);
+            }
+            Number::Channel6 => {
+                set_output_enabled!(self, l, 6, enabled);
+                update_channel!(self, 6);
+            }
+            Number::Channel7 => {
+                set_output_enabled!(self, l, 7, enabled);
+                update_channel!(self, 7);
+            }
+        };
+    }
+
+    /// Set the channel's hpoint and duty together
+    fn set_hpoint_and_duty_hw(&self, hpoint: u32, duty: u32) {
+        match self.number {
+            Number::Channel0 => {
+                set_hpoint_and_duty!(self, l, 0, hpoint, duty);
+                update_channel!(self, 0);
+            }
+            Number::Channel1 => {
+                set_hpoint_and_duty!(self, l, 1, hpoint, duty);
+                update_channel!(self, 1);
+            }
+            Number::Channel2 => {
+                set_hpoint_and_duty!(self, l, 2, hpoint, duty);
+                update_channel!(self, 2);
+            }
+            Number::Channel3 => {
+                set_hpoint_and_duty!(self, l, 3, hpoint, duty);
+                update_channel!(self, 3);
+            }
+            Number::Channel4 => {
+                set_hpoint_and_duty!(self, l, 4, hpoint, duty);
+                update_channel!(self, 4);
+            }
+            Number::Channel5 => {
+                set_hpoint_and_duty!(self, l, 5, hpoint, duty);
+                update_channel!(self, 5);
+            }
+            Number::Channel6 => {
+                set_hpoint_and_duty!(self, l, 6, hpoint, duty);
+                update_channel!(self, 6);
+            }
+            Number::Channel7 => {
+                set_hpoint_and_duty!(self, l, 7, hpoint, duty);
+                update_channel!(self, 7);
+            }
+        };
+    }
+
+    /// Program and start a hardware duty fade
+    fn set_fade_hw(&self, start_duty: u32, direction: FadeDirection, duty_num: u32, duty_cycle: u32, duty_scale: u32) {
+        let duty_inc = direction == FadeDirection::Increase;
+        match self.number {
+            Number::Channel0 => {
+                set_fade!(self, l, 0, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 0);
+            }
+            Number::Channel1 => {
+                set_fade!(self, l, 1, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 1);
+            }
+            Number::Channel2 => {
+                set_fade!(self, l, 2, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 2);
+            }
+            Number::Channel3 => {
+                set_fade!(self, l, 3, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 3);
+            }
+            Number::Channel4 => {
+                set_fade!(self, l, 4, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 4);
+            }
+            Number::Channel5 => {
+                set_fade!(self, l, 5, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 5);
+            }
+            Number::Channel6 => {
+                set_fade!(self, l, 6, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 6);
+            }
+            Number::Channel7 => {
+                set_fade!(self, l, 7, start_duty, duty_inc, duty_num, duty_cycle, duty_scale);
+                update_channel!(self, 7);
+            }
+        };
+    }
+
+    /// Enable the fade-done (`duty_chng_end`) interrupt for this channel
+    fn enable_fade_interrupt(&self) {
+        match self.number {
+            Number::Channel0 => set_fade_interrupt_enabled!(self.ledc, l, 0, true),
+            Number::Channel1 => set_fade_interrupt_enabled!(self.ledc, l, 1, true),
+            Number::Channel2 => set_fade_interrupt_enabled!(self.ledc, l, 2, true),
+            Number::Channel3 => set_fade_interrupt_enabled!(self.ledc, l, 3, true),
+            Number::Channel4 => set_fade_interrupt_enabled!(self.ledc, l, 4, true),
+            Number::Channel5 => set_fade_interrupt_enabled!(self.ledc, l, 5, true),
+            Number::Channel6 => set_fade_interrupt_enabled!(self.ledc, l, 6, true),
+            Number::Channel7 => set_fade_interrupt_enabled!(self.ledc, l, 7, true),
+        };
+    }
+
+    /// Clear a pending fade-done interrupt for this channel
+    fn clear_fade_interrupt(&self) {
+        match self.number {
+            Number::Channel0 => clear_fade_interrupt!(self.ledc, l, 0),
+            Number::Channel1 => clear_fade_interrupt!(self.ledc, l, 1),
+            Number::Channel2 => clear_fade_interrupt!(self.ledc, l, 2),
+            Number::Channel3 => clear_fade_interrupt!(self.ledc, l, 3),
+            Number::Channel4 => clear_fade_interrupt!(self.ledc, l, 4),
+            Number::Channel5 => clear_fade_interrupt!(self.ledc, l, 5),
+            Number::Channel6 => clear_fade_interrupt!(self.ledc, l, 6),
+            Number::Channel7 => clear_fade_interrupt!(self.ledc, l, 7),
+        };
+    }
+
+    /// Check whether the fade-done interrupt status bit is set
+    fn is_fade_done(&self) -> bool {
+        match self.number {
+            Number::Channel0 => is_fade_interrupt_set!(self.ledc, l, 0),
+            Number::Channel1 => is_fade_interrupt_set!(self.ledc, l, 1),
+            Number::Channel2 => is_fade_interrupt_set!(self.ledc, l, 2),
+            Number::Channel3 => is_fade_interrupt_set!(self.ledc, l, 3),
+            Number::Channel4 => is_fade_interrupt_set!(self.ledc, l, 4),
+            Number::Channel5 => is_fade_interrupt_set!(self.ledc, l, 5),
+            Number::Channel6 => is_fade_interrupt_set!(self.ledc, l, 6),
+            Number::Channel7 => is_fade_interrupt_set!(self.ledc, l, 7),
+        }
+    }
+
+    /// This channel's index into [`FADE_WAKERS`]
+    fn fade_waker_index(&self) -> usize {
+        fade_waker_index(ChannelBank::LowSpeed, self.number)
+    }
+}
+
+/// A pair of channels sharing a single timer, where one channel drives the
+/// logical inverse of the other with a configurable dead-time gap inserted on
+/// both switching edges so the two outputs never drive high at the same time.
+///
+/// Useful for driving half-bridge (motor/gate-driver) loads where the two
+/// sides must never conduct simultaneously. LEDC has no native dead-time
+/// generator, so this works by giving the channels complementary duties and
+/// offset `hpoint` values: the complementary channel's active window starts
+/// `dead_time` LSBs after the primary channel's window ends and stops
+/// `dead_time` LSBs before the end of the PWM period.
+pub struct ComplementaryChannel<'a, S: TimerSpeed, O1: OutputPin, O2: OutputPin> {
+    primary: Channel<'a, S, O1>,
+    complementary: Channel<'a, S, O2>,
+    dead_time: u32,
+    duty: core::cell::Cell<u32>,
+}
+
+impl<'a, S: TimerSpeed, O1: OutputPin, O2: OutputPin> ComplementaryChannel<'a, S, O1, O2>
+where
+    Channel<'a, S, O1>: ChannelHW<O1>,
+    Channel<'a, S, O2>: ChannelHW<O2>,
+{
+    /// Pair up two channels; [`Self::configure`] binds both to the same timer
+    pub fn new(primary: Channel<'a, S, O1>, complementary: Channel<'a, S, O2>, dead_time: u32) -> Self {
+        ComplementaryChannel {
+            primary,
+            complementary,
+            dead_time,
+            duty: core::cell::Cell::new(0),
+        }
+    }
+
+    /// Configure both channels and set the initial shared duty
+    pub fn configure(&mut self, config: config::Config<'a, S>) -> Result<(), Error> {
+        self.primary.configure(config)?;
+        self.complementary.configure(config)?;
+
+        self.set_duty(config.duty_pct)
+    }
+
+    /// Change the dead-time (in timer LSBs) inserted on both switching edges
+    /// and re-derive the channels' `hpoint`/`duty` at the current duty
+    pub fn set_dead_time(&mut self, dead_time: u32) -> Result<(), Error> {
+        self.dead_time = dead_time;
+        self.set_duty_raw(self.duty.get())
+    }
+
+    /// Set the shared duty % of the pair
+    pub fn set_duty(&self, duty_pct: f32) -> Result<(), Error> {
+        let max_duty = self.primary.get_max_duty()?;
+        self.set_duty_raw((max_duty as f32 * duty_pct) as u32)
+    }
+
+    /// Set the shared duty as a raw count of timer LSBs and re-derive both
+    /// channels' `hpoint`/`duty`, validating that both dead-time gaps fit
+    /// within the timer's range
+    pub fn set_duty_raw(&self, duty: u32) -> Result<(), Error> {
+        let max_duty = self.primary.get_max_duty()?;
+
+        if duty + 2 * self.dead_time > max_duty {
+            return Err(Error::Duty);
+        }
+
+        // Primary is active [0, duty). Complementary is the inverse, with a
+        // dead-time gap after the primary's falling edge and before its own
+        // rising edge, so it's active [duty + dead_time, max_duty - dead_time).
+        let complementary_hpoint = duty + self.dead_time;
+        let complementary_duty = max_duty - complementary_hpoint - self.dead_time;
+
+        self.primary.set_hpoint_and_duty_hw(0, duty);
+        self.complementary.set_hpoint_and_duty_hw(complementary_hpoint, complementary_duty);
+        self.duty.set(duty);
+
+        Ok(())
+    }
+}
+
+/// Adapts a configured [`Channel`] to `embedded-hal`'s generic PWM pin trait,
+/// for use with drivers written against [`embedded_hal::PwmPin`] (servo, RGB
+/// LED, buzzer, ...).
+impl<'a, S: TimerSpeed, O: OutputPin> embedded_hal::PwmPin for Channel<'a, S, O>
+where
+    Channel<'a, S, O>: ChannelHW<O>,
+{
+    type Duty = u32;
+
+    /// Disable the channel output
+    fn disable(&mut self) {
+        self.set_output_enabled_hw(false);
+    }
+
+    /// Enable the channel output
+    fn enable(&mut self) {
+        self.set_output_enabled_hw(true);
+    }
+
+    /// Get the current duty, in raw timer LSBs
+    fn get_duty(&self) -> Self::Duty {
+        self.get_duty_hw()
+    }
+
+    /// Get the maximum duty, in raw timer LSBs, as cached at `configure` time.
+    /// Reads as `0` if the channel hasn't been configured yet.
+    fn get_max_duty(&self) -> Self::Duty {
+        self.max_duty.get()
+    }
+
+    /// Set the duty, in raw timer LSBs
+    fn set_duty(&mut self, duty: Self::Duty) {
+        self.set_duty_hw(duty);
+    }
 }